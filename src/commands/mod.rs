@@ -1,8 +1,11 @@
 use crate::{Authentication, AuthenticationError};
+use base64::Engine;
 use prettytable::{cell, Cell, Row};
+use rand::Rng;
 use std::collections::HashMap;
 
 use log::debug;
+use std::fmt;
 use std::fs;
 use std::fs::File;
 use std::io::Write;
@@ -22,9 +25,14 @@ mod edge_app_utils;
 pub(crate) mod playlist;
 pub mod screen;
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum OutputType {
     HumanReadable,
     Json,
+    Csv,
+    Tsv,
+    #[cfg(feature = "yaml")]
+    Yaml,
 }
 
 pub trait Formatter {
@@ -46,7 +54,10 @@ fn format_value<T, F>(
 ) -> String
 where
     T: FormatterValue,
-    F: Fn(&str, &serde_json::Value) -> Cell, // Takes field name and field value and returns display representation
+    // Takes the output type, field name and field value, and returns a display representation.
+    // Receiving the output type lets a single transformer render e.g. a bool
+    // as an emoji for humans but as `true`/`false` for machine-readable formats.
+    F: Fn(OutputType, &str, &serde_json::Value) -> Cell,
 {
     match output_type {
         OutputType::HumanReadable => {
@@ -58,7 +69,7 @@ where
                     let mut row_content = Vec::new();
                     for field in &field_names {
                         let display_value = if let Some(transformer) = &value_transformer {
-                            transformer(field, &v[field])
+                            transformer(output_type, field, &v[field])
                         } else {
                             Cell::new(v[field].as_str().unwrap_or("N/A"))
                         };
@@ -70,6 +81,84 @@ where
             table.to_string()
         }
         OutputType::Json => serde_json::to_string_pretty(&value.value()).unwrap(),
+        OutputType::Csv => {
+            write_delimited(b',', &column_names, &field_names, value, &value_transformer)
+        }
+        OutputType::Tsv => write_delimited(
+            b'\t',
+            &column_names,
+            &field_names,
+            value,
+            &value_transformer,
+        ),
+        #[cfg(feature = "yaml")]
+        OutputType::Yaml => serde_yaml::to_string(&value.value()).unwrap(),
+    }
+}
+
+// Shared CSV/TSV writer backing the `Csv`/`Tsv` output types: same header row
+// and per-field projection as the human-readable table, quoted/escaped per
+// RFC 4180 via the `csv` crate.
+fn write_delimited<T, F>(
+    delimiter: u8,
+    column_names: &[&str],
+    field_names: &[&str],
+    value: &T,
+    value_transformer: &Option<F>,
+) -> String
+where
+    T: FormatterValue,
+    F: Fn(OutputType, &str, &serde_json::Value) -> Cell,
+{
+    let output_type = if delimiter == b'\t' {
+        OutputType::Tsv
+    } else {
+        OutputType::Csv
+    };
+
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .from_writer(vec![]);
+    writer.write_record(column_names).unwrap();
+
+    if let Some(values) = value.value().as_array() {
+        for v in values {
+            let row: Vec<String> = field_names
+                .iter()
+                .map(|field| {
+                    let cell = if let Some(transformer) = value_transformer {
+                        transformer(output_type, field, &v[field])
+                    } else {
+                        Cell::new(v[field].as_str().unwrap_or("N/A"))
+                    };
+                    cell.get_content()
+                })
+                .collect();
+            writer.write_record(&row).unwrap();
+        }
+    }
+
+    String::from_utf8(writer.into_inner().unwrap()).unwrap()
+}
+
+// Renders a boolean as an emoji for humans, or as `true`/`false` for formats
+// meant to be consumed by other tooling.
+fn bool_cell_text(output_type: OutputType, value: bool) -> &'static str {
+    match output_type {
+        OutputType::HumanReadable => {
+            if value {
+                "✅"
+            } else {
+                "❌"
+            }
+        }
+        _ => {
+            if value {
+                "true"
+            } else {
+                "false"
+            }
+        }
     }
 }
 
@@ -101,6 +190,241 @@ pub enum CommandError {
     FileSystemError(String),
     #[error("Asset processing timeout")]
     AssetProcessingTimeout,
+    #[error("parse error: could not decode base64 payload with any known encoding")]
+    Base64Decode,
+    #[error("Setting default value '{default_value}' is not a valid {setting_type}")]
+    InvalidSettingDefaultValue {
+        setting_type: String,
+        default_value: String,
+    },
+    #[error("giving up after {attempts} attempts, last response status: {last_status}")]
+    RetriesExhausted { attempts: u32, last_status: u16 },
+    #[cfg(feature = "ts-export")]
+    #[error("TypeScript export error: {0}")]
+    TsExport(#[from] ts_rs::ExportError),
+}
+
+const DEFAULT_RETRY_MAX_ATTEMPTS: u32 = 3;
+const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+const DEFAULT_RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum RetryPolicy {
+    // Safe to retry on any retryable transport error or status.
+    Idempotent,
+    // The request may have already been applied server-side, so only retry
+    // when we know it wasn't: a connection-level failure before any bytes
+    // reached the server, or an explicit 429/503 rejection.
+    NonIdempotent,
+}
+
+// Attempts/base/cap, overridable per-`Authentication` via `Config`; falls
+// back to the `DEFAULT_RETRY_*` constants when unset. `max_attempts` counts
+// retries after the first request, so up to `max_attempts + 1` requests are sent.
+#[derive(Clone, Copy, Debug)]
+struct RetryConfig {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl RetryConfig {
+    fn from_authentication(authentication: &Authentication) -> Self {
+        Self {
+            max_attempts: authentication
+                .config
+                .retry_max_attempts
+                .unwrap_or(DEFAULT_RETRY_MAX_ATTEMPTS),
+            base_delay: authentication
+                .config
+                .retry_base_delay_ms
+                .map(Duration::from_millis)
+                .unwrap_or(DEFAULT_RETRY_BASE_DELAY),
+            max_delay: authentication
+                .config
+                .retry_max_delay_ms
+                .map(Duration::from_millis)
+                .unwrap_or(DEFAULT_RETRY_MAX_DELAY),
+        }
+    }
+}
+
+fn is_retryable_status(status: StatusCode, policy: RetryPolicy) -> bool {
+    match policy {
+        RetryPolicy::Idempotent => matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::INTERNAL_SERVER_ERROR
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        ),
+        RetryPolicy::NonIdempotent => {
+            matches!(
+                status,
+                StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+            )
+        }
+    }
+}
+
+fn is_retryable_transport_error(err: &reqwest::Error, policy: RetryPolicy) -> bool {
+    match policy {
+        RetryPolicy::Idempotent => err.is_connect() || err.is_timeout(),
+        // A timeout may have fired after the request reached the server, so
+        // only a pre-send connection failure is safe to retry here.
+        RetryPolicy::NonIdempotent => err.is_connect(),
+    }
+}
+
+// Honors a `Retry-After` header in either its delta-seconds or HTTP-date
+// form, falling back to our own backoff when the header is absent or
+// unparseable.
+fn retry_after_delay(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let deadline = httpdate::parse_http_date(value).ok()?;
+    Some(
+        deadline
+            .duration_since(std::time::SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+// Exponential backoff with full jitter: a random delay in `[0, base * 2^attempt]`,
+// capped at `retry.max_delay`.
+fn backoff_delay(attempt: u32, retry: RetryConfig) -> Duration {
+    let exp = retry
+        .base_delay
+        .saturating_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX));
+    let capped = exp.min(retry.max_delay);
+    Duration::from_millis(rand::thread_rng().gen_range(0..=capped.as_millis() as u64))
+}
+
+// Shared retry loop used by `get`/`post`/`patch`/`delete`: retries transient
+// transport errors and retryable status codes (429/500/502/503/504, subject
+// to `policy`) with exponential backoff and full jitter, honoring any
+// `Retry-After` header the server sends. Non-retryable outcomes (including a
+// non-retryable status code) are returned as-is so the caller keeps producing
+// its usual, method-specific error.
+fn send_with_retry(
+    policy: RetryPolicy,
+    retry: RetryConfig,
+    mut send: impl FnMut() -> reqwest::Result<reqwest::blocking::Response>,
+) -> Result<reqwest::blocking::Response, CommandError> {
+    let mut attempt = 0;
+    loop {
+        match send() {
+            Ok(response) => {
+                let status = response.status();
+                if !is_retryable_status(status, policy) {
+                    return Ok(response);
+                }
+                if attempt >= retry.max_attempts {
+                    return Err(CommandError::RetriesExhausted {
+                        attempts: attempt + 1,
+                        last_status: status.as_u16(),
+                    });
+                }
+                let delay = retry_after_delay(response.headers())
+                    .unwrap_or_else(|| backoff_delay(attempt, retry));
+                std::thread::sleep(delay);
+                attempt += 1;
+            }
+            Err(err) => {
+                if !is_retryable_transport_error(&err, policy) || attempt >= retry.max_attempts {
+                    return Err(err.into());
+                }
+                std::thread::sleep(backoff_delay(attempt, retry));
+                attempt += 1;
+            }
+        }
+    }
+}
+
+// Binary payload (e.g. the manifest `icon`) round-tripped through JSON/YAML
+// as base64 text: canonical (URL-safe, no padding) on encode, permissive on decode.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Base64Data(pub Vec<u8>);
+
+impl Base64Data {
+    // Tries every base64 flavor we've seen a backend emit, in order, and
+    // returns the first one that decodes cleanly.
+    fn decode(s: &str) -> Result<Vec<u8>, CommandError> {
+        use base64::engine::general_purpose::{
+            STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD,
+        };
+
+        if let Ok(bytes) = STANDARD.decode(s) {
+            return Ok(bytes);
+        }
+        if let Ok(bytes) = URL_SAFE.decode(s) {
+            return Ok(bytes);
+        }
+        if let Ok(bytes) = URL_SAFE_NO_PAD.decode(s) {
+            return Ok(bytes);
+        }
+        // MIME wraps output in CRLF every 76 characters; strip whitespace and
+        // retry with the standard alphabet.
+        let stripped: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        if stripped != s {
+            if let Ok(bytes) = STANDARD.decode(&stripped) {
+                return Ok(bytes);
+            }
+        }
+        STANDARD_NO_PAD
+            .decode(s)
+            .map_err(|_| CommandError::Base64Decode)
+    }
+}
+
+impl fmt::Display for Base64Data {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(&self.0)
+        )
+    }
+}
+
+impl TryFrom<&str> for Base64Data {
+    type Error = CommandError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(Self(Self::decode(value)?))
+    }
+}
+
+impl AsRef<[u8]> for Base64Data {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Serialize for Base64Data {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Base64Data {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::decode(&s)
+            .map(Base64Data)
+            .map_err(serde::de::Error::custom)
+    }
 }
 
 pub fn get(
@@ -110,12 +434,12 @@ pub fn get(
     let url = format!("{}/{}", &authentication.config.url, endpoint);
     let mut headers = HeaderMap::new();
     headers.insert("Prefer", "return=representation".parse()?);
+    let client = authentication.build_client()?;
+    let retry = RetryConfig::from_authentication(authentication);
 
-    let response = authentication
-        .build_client()?
-        .get(url)
-        .headers(headers)
-        .send()?;
+    let response = send_with_retry(RetryPolicy::Idempotent, retry, || {
+        client.get(&url).headers(headers.clone()).send()
+    })?;
 
     let status = response.status();
 
@@ -134,13 +458,18 @@ pub fn post<T: Serialize + ?Sized>(
     let url = format!("{}/{}", &authentication.config.url, endpoint);
     let mut headers = HeaderMap::new();
     headers.insert("Prefer", "return=representation".parse()?);
-
-    let response = authentication
-        .build_client()?
-        .post(url)
-        .headers(headers)
-        .json(&payload)
-        .send()?;
+    let client = authentication.build_client()?;
+    let retry = RetryConfig::from_authentication(authentication);
+
+    // `post` isn't necessarily idempotent on the backend, so it only retries
+    // when we know the request was never applied.
+    let response = send_with_retry(RetryPolicy::NonIdempotent, retry, || {
+        client
+            .post(&url)
+            .headers(headers.clone())
+            .json(&payload)
+            .send()
+    })?;
 
     let status = response.status();
 
@@ -158,7 +487,13 @@ pub fn post<T: Serialize + ?Sized>(
 
 pub fn delete(authentication: &Authentication, endpoint: &str) -> anyhow::Result<(), CommandError> {
     let url = format!("{}/{}", &authentication.config.url, endpoint);
-    let response = authentication.build_client()?.delete(url).send()?;
+    let client = authentication.build_client()?;
+    let retry = RetryConfig::from_authentication(authentication);
+
+    let response = send_with_retry(RetryPolicy::Idempotent, retry, || {
+        client.delete(&url).send()
+    })?;
+
     if ![StatusCode::OK, StatusCode::NO_CONTENT].contains(&response.status()) {
         return Err(CommandError::WrongResponseStatus(
             response.status().as_u16(),
@@ -175,13 +510,16 @@ pub fn patch<T: Serialize + ?Sized>(
     let url = format!("{}/{}", &authentication.config.url, endpoint);
     let mut headers = HeaderMap::new();
     headers.insert("Prefer", "return=representation".parse()?);
+    let client = authentication.build_client()?;
+    let retry = RetryConfig::from_authentication(authentication);
 
-    let response = authentication
-        .build_client()?
-        .patch(url)
-        .json(&payload)
-        .headers(headers)
-        .send()?;
+    let response = send_with_retry(RetryPolicy::Idempotent, retry, || {
+        client
+            .patch(&url)
+            .json(&payload)
+            .headers(headers.clone())
+            .send()
+    })?;
 
     let status = response.status();
     if status != StatusCode::OK {
@@ -199,12 +537,52 @@ pub fn patch<T: Serialize + ?Sized>(
     }
 }
 
+// Typed counterparts of `get`/`post`, for callers with a struct matching the
+// response shape; a schema mismatch then surfaces as `CommandError::Parse`.
+pub fn get_typed<T: serde::de::DeserializeOwned>(
+    authentication: &Authentication,
+    endpoint: &str,
+) -> Result<T, CommandError> {
+    Ok(serde_json::from_value(get(authentication, endpoint)?)?)
+}
+
+pub fn post_typed<T: Serialize + ?Sized, R: serde::de::DeserializeOwned>(
+    authentication: &Authentication,
+    endpoint: &str,
+    payload: &T,
+) -> Result<R, CommandError> {
+    Ok(serde_json::from_value(post(
+        authentication,
+        endpoint,
+        payload,
+    )?)?)
+}
+
+// Writes TypeScript bindings for the manifest types to `out_dir`, one `.ts` file per type.
+#[cfg(feature = "ts-export")]
+pub fn export_typescript_bindings(out_dir: &Path) -> Result<(), CommandError> {
+    use ts_rs::TS;
+
+    fs::create_dir_all(out_dir)?;
+    EdgeAppManifest::export_to(out_dir.join("EdgeAppManifest.ts"))?;
+    Setting::export_to(out_dir.join("Setting.ts"))?;
+    PlaylistItem::export_to(out_dir.join("PlaylistItem.ts"))?;
+    PlaylistFile::export_to(out_dir.join("PlaylistFile.ts"))?;
+    Ok(())
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-export", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-export", ts(export))]
 pub struct EdgeAppManifest {
     pub app_id: String,
     pub user_version: String,
     pub description: String,
-    pub icon: String,
+    // `Base64Data` has hand-written (de)serialization, which ts-rs can't
+    // introspect, so the field is exported as the plain string it
+    // serializes to (see the equivalent override on `Setting::type_`).
+    #[cfg_attr(feature = "ts-export", ts(type = "string"))]
+    pub icon: Base64Data,
     pub author: String,
     pub homepage_url: String,
     #[serde(
@@ -212,14 +590,22 @@ pub struct EdgeAppManifest {
         deserialize_with = "deserialize_settings",
         default
     )]
+    // Serialized as a map keyed by title (see `serialize_settings`), not an
+    // array, so the generated TS type needs to reflect that shape explicitly.
+    #[cfg_attr(feature = "ts-export", ts(type = "Record<string, Setting>"))]
     pub settings: Vec<Setting>,
 }
 
 // maybe we can use a better name as we have EdgeAppSettings which is the same but serde_json::Value inside
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-export", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-export", ts(export))]
 pub struct Setting {
     #[serde(rename = "type")]
-    pub type_: String,
+    // `SettingType` has hand-written (de)serialization, which ts-rs can't
+    // introspect, so the field is exported as the plain string it serializes to.
+    #[cfg_attr(feature = "ts-export", ts(rename = "type", type = "string"))]
+    pub type_: SettingType,
     #[serde(default)]
     pub default_value: String,
     #[serde(default)]
@@ -228,6 +614,98 @@ pub struct Setting {
     pub help_text: String,
 }
 
+// The set of setting types the backend understands; `Unknown` is a catch-all
+// so a manifest written against a newer schema still round-trips. No
+// `ts_rs::TS` derive: the (de)serialization below is hand-written, so
+// `Setting::type_`'s field-level `ts(type = "string")` override is used instead.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum SettingType {
+    #[default]
+    String,
+    Secret,
+    Bool,
+    Number,
+    Json,
+    Unknown(String),
+}
+
+impl Serialize for SettingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.canonical_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for SettingType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(SettingType::from(
+            String::deserialize(deserializer)?.as_str(),
+        ))
+    }
+}
+
+impl From<&str> for SettingType {
+    fn from(value: &str) -> Self {
+        match value {
+            "string" => SettingType::String,
+            "secret" => SettingType::Secret,
+            "bool" => SettingType::Bool,
+            "number" => SettingType::Number,
+            "json" => SettingType::Json,
+            other => SettingType::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for SettingType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.canonical_name())
+    }
+}
+
+impl SettingType {
+    fn canonical_name(&self) -> &str {
+        match self {
+            SettingType::String => "string",
+            SettingType::Secret => "secret",
+            SettingType::Bool => "bool",
+            SettingType::Number => "number",
+            SettingType::Json => "json",
+            SettingType::Unknown(s) => s,
+        }
+    }
+
+    // Checks that `default_value` is a value this type can actually hold.
+    // `String`/`Secret`/`Unknown` accept any text, so there's nothing to
+    // validate for them.
+    fn validate_default_value(&self, default_value: &str) -> Result<(), CommandError> {
+        if default_value.is_empty() {
+            return Ok(());
+        }
+
+        let is_valid = match self {
+            SettingType::Number => default_value.parse::<f64>().is_ok(),
+            SettingType::Bool => matches!(default_value, "true" | "false"),
+            SettingType::Json => serde_json::from_str::<serde_json::Value>(default_value).is_ok(),
+            SettingType::String | SettingType::Secret | SettingType::Unknown(_) => true,
+        };
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(CommandError::InvalidSettingDefaultValue {
+                setting_type: self.canonical_name().to_string(),
+                default_value: default_value.to_string(),
+            })
+        }
+    }
+}
+
 fn deserialize_settings<'de, D>(deserializer: D) -> Result<Vec<Setting>, D::Error>
 where
     D: Deserializer<'de>,
@@ -261,6 +739,11 @@ impl EdgeAppManifest {
     pub fn new(path: &Path) -> Result<Self, CommandError> {
         let data = fs::read_to_string(path)?;
         let manifest: EdgeAppManifest = serde_yaml::from_str(&data)?;
+        for setting in &manifest.settings {
+            setting
+                .type_
+                .validate_default_value(&setting.default_value)?;
+        }
         Ok(manifest)
     }
 
@@ -273,6 +756,8 @@ impl EdgeAppManifest {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-export", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-export", ts(export))]
 pub struct PlaylistItem {
     pub asset_id: String,
     #[serde(deserialize_with = "deserialize_float_to_u32")]
@@ -294,6 +779,8 @@ where
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-export", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-export", ts(export))]
 pub struct PlaylistFile {
     predicate: String,
     playlist_id: String,
@@ -314,14 +801,28 @@ impl PlaylistFile {
     }
 }
 
+// Hand-written typed model of a single row returned by the edge apps list
+// endpoint, used for the human-readable table. If any row lacks one of these
+// fields, `EdgeApps::new` keeps an empty list and that table shows no rows;
+// no error is reported, and the other output types still read `value` directly.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EdgeApp {
+    pub id: String,
+    pub name: String,
+}
+
 #[derive(Debug)]
 pub struct EdgeApps {
     pub value: serde_json::Value,
+    // Typed view of `value`, used by `Formatter::format`'s `HumanReadable`
+    // path instead of indexing `value` by string key.
+    apps: Vec<EdgeApp>,
 }
 
 impl EdgeApps {
     pub fn new(value: serde_json::Value) -> Self {
-        Self { value }
+        let apps = serde_json::from_value(value.clone()).unwrap_or_default();
+        Self { value, apps }
     }
 }
 impl FormatterValue for EdgeApps {
@@ -332,12 +833,21 @@ impl FormatterValue for EdgeApps {
 
 impl Formatter for EdgeApps {
     fn format(&self, output_type: OutputType) -> String {
+        if let OutputType::HumanReadable = output_type {
+            let mut table = prettytable::Table::new();
+            table.add_row(Row::from(vec!["Id", "Title"]));
+            for app in &self.apps {
+                table.add_row(Row::new(vec![Cell::new(&app.id), Cell::new(&app.name)]));
+            }
+            return table.to_string();
+        }
+
         format_value(
             output_type,
             vec!["Id", "Title"],
             vec!["id", "name"],
             self,
-            None::<fn(&str, &serde_json::Value) -> Cell>,
+            None::<fn(OutputType, &str, &serde_json::Value) -> Cell>,
         )
     }
 }
@@ -365,18 +875,20 @@ impl Formatter for EdgeAppVersions {
             vec!["Revision", "Description", "Published"],
             vec!["revision", "description", "published"],
             self,
-            Some(|field_name: &str, field_value: &serde_json::Value| {
-                if field_name.eq("revision") {
-                    let version = field_value.as_u64().unwrap_or(0);
-                    let str_version = version.to_string();
-                    Cell::new(if version > 0 { &str_version } else { "N/A" })
-                } else if field_name.eq("published") {
-                    let published = field_value.as_bool().unwrap_or(false);
-                    Cell::new(if published { "✅" } else { "❌" })
-                } else {
-                    Cell::new(field_value.as_str().unwrap_or("N/A"))
-                }
-            }),
+            Some(
+                |output_type: OutputType, field_name: &str, field_value: &serde_json::Value| {
+                    if field_name.eq("revision") {
+                        let version = field_value.as_u64().unwrap_or(0);
+                        let str_version = version.to_string();
+                        Cell::new(if version > 0 { &str_version } else { "N/A" })
+                    } else if field_name.eq("published") {
+                        let published = field_value.as_bool().unwrap_or(false);
+                        Cell::new(bool_cell_text(output_type, published))
+                    } else {
+                        Cell::new(field_value.as_str().unwrap_or("N/A"))
+                    }
+                },
+            ),
         )
     }
 }
@@ -419,10 +931,22 @@ impl Formatter for EdgeAppSettings {
             ],
             self,
             Some(
-                |field_name: &str, field_value: &serde_json::Value| -> Cell {
+                |output_type: OutputType,
+                 field_name: &str,
+                 field_value: &serde_json::Value|
+                 -> Cell {
                     if field_name.eq("optional") {
                         let value = field_value.as_bool().unwrap_or(false);
-                        return Cell::new(if value { "Yes" } else { "No" });
+                        return Cell::new(match output_type {
+                            OutputType::HumanReadable => {
+                                if value {
+                                    "Yes"
+                                } else {
+                                    "No"
+                                }
+                            }
+                            _ => bool_cell_text(output_type, value),
+                        });
                     }
                     Cell::new(field_value.as_str().unwrap_or_default())
                 },
@@ -431,14 +955,31 @@ impl Formatter for EdgeAppSettings {
     }
 }
 
+// Hand-written typed model of a single row returned by the assets list
+// endpoint, used for the human-readable table. If any row lacks one of these
+// fields, `Assets::new` keeps an empty list and that table shows no rows;
+// no error is reported, and the other output types still read `value` directly.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Asset {
+    pub id: String,
+    pub title: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub status: String,
+}
+
 #[derive(Debug)]
 pub struct Assets {
     pub value: serde_json::Value,
+    // Typed view of `value`, used by `Formatter::format`'s `HumanReadable`
+    // path instead of indexing `value` by string key.
+    assets: Vec<Asset>,
 }
 
 impl Assets {
     pub fn new(value: serde_json::Value) -> Self {
-        Self { value }
+        let assets = serde_json::from_value(value.clone()).unwrap_or_default();
+        Self { value, assets }
     }
 }
 
@@ -450,24 +991,56 @@ impl FormatterValue for Assets {
 
 impl Formatter for Assets {
     fn format(&self, output_type: OutputType) -> String {
+        if let OutputType::HumanReadable = output_type {
+            let mut table = prettytable::Table::new();
+            table.add_row(Row::from(vec!["Id", "Title", "Type", "Status"]));
+            for asset in &self.assets {
+                table.add_row(Row::new(vec![
+                    Cell::new(&asset.id),
+                    Cell::new(&asset.title),
+                    Cell::new(&asset.type_),
+                    Cell::new(&asset.status),
+                ]));
+            }
+            return table.to_string();
+        }
+
         format_value(
             output_type,
             vec!["Id", "Title", "Type", "Status"],
             vec!["id", "title", "type", "status"],
             self,
-            None::<fn(&str, &serde_json::Value) -> Cell>,
+            None::<fn(OutputType, &str, &serde_json::Value) -> Cell>,
         )
     }
 }
 
+// Hand-written typed model of a single row returned by the screens list
+// endpoint, used for the human-readable table. If any row lacks one of these
+// fields, `Screens::new` keeps an empty list and that table shows no rows;
+// no error is reported, and the other output types still read `value` directly.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Screen {
+    pub id: String,
+    pub name: String,
+    pub hardware_version: String,
+    pub in_sync: bool,
+    pub last_ping: String,
+    pub uptime: u64,
+}
+
 #[derive(Debug)]
 pub struct Screens {
     pub value: serde_json::Value,
+    // Typed view of `value`, used by `Formatter::format`'s `HumanReadable`
+    // path instead of indexing `value` by string key.
+    screens: Vec<Screen>,
 }
 
 impl Screens {
     pub fn new(value: serde_json::Value) -> Self {
-        Self { value }
+        let screens = serde_json::from_value(value.clone()).unwrap_or_default();
+        Self { value, screens }
     }
 }
 
@@ -479,6 +1052,32 @@ impl FormatterValue for Screens {
 
 impl Formatter for Screens {
     fn format(&self, output_type: OutputType) -> String {
+        if let OutputType::HumanReadable = output_type {
+            let mut table = prettytable::Table::new();
+            table.add_row(Row::from(vec![
+                "Id",
+                "Name",
+                "Hardware Version",
+                "In Sync",
+                "Last Ping",
+                "Uptime",
+            ]));
+            for screen in &self.screens {
+                table.add_row(Row::new(vec![
+                    Cell::new(&screen.id),
+                    Cell::new(&screen.name),
+                    Cell::new(&screen.hardware_version),
+                    Cell::new(bool_cell_text(output_type, screen.in_sync)).style_spec("c"),
+                    Cell::new(&screen.last_ping),
+                    Cell::new(
+                        &indicatif::HumanDuration(Duration::new(screen.uptime, 0)).to_string(),
+                    )
+                    .style_spec("r"),
+                ]));
+            }
+            return table.to_string();
+        }
+
         format_value(
             output_type,
             vec![
@@ -498,24 +1097,23 @@ impl Formatter for Screens {
                 "uptime",
             ],
             self,
-            Some(|field: &str, value: &serde_json::Value| {
-                if field.eq("in_sync") {
-                    if value.as_bool().unwrap_or(false) {
-                        cell!(c -> "✅")
+            Some(
+                |output_type: OutputType, field: &str, value: &serde_json::Value| {
+                    if field.eq("in_sync") {
+                        let synced = value.as_bool().unwrap_or(false);
+                        Cell::new(bool_cell_text(output_type, synced)).style_spec("c")
+                    } else if field.eq("uptime") {
+                        let uptime = if let Some(uptime) = value.as_u64() {
+                            indicatif::HumanDuration(Duration::new(uptime, 0)).to_string()
+                        } else {
+                            "N/A".to_owned()
+                        };
+                        Cell::new(&uptime).style_spec("r")
                     } else {
-                        cell!(c -> "❌")
+                        Cell::new(value.as_str().unwrap_or("N/A"))
                     }
-                } else if field.eq("uptime") {
-                    let uptime = if let Some(uptime) = value.as_u64() {
-                        indicatif::HumanDuration(Duration::new(uptime, 0)).to_string()
-                    } else {
-                        "N/A".to_owned()
-                    };
-                    Cell::new(&uptime).style_spec("r")
-                } else {
-                    Cell::new(value.as_str().unwrap_or("N/A"))
-                }
-            }),
+                },
+            ),
         )
     }
 }
@@ -544,7 +1142,7 @@ impl Formatter for Playlists {
             vec!["Id", "Title"],
             vec!["id", "title"],
             self,
-            None::<fn(&str, &serde_json::Value) -> Cell>,
+            None::<fn(OutputType, &str, &serde_json::Value) -> Cell>,
         )
     }
 }
@@ -573,16 +1171,18 @@ impl Formatter for PlaylistItems {
             vec!["Asset Id", "Duration"],
             vec!["asset_id", "duration"],
             self,
-            Some(|field: &str, value: &serde_json::Value| {
-                if field.eq("duration") {
-                    cell!(indicatif::HumanDuration(Duration::from_secs(
-                        value.as_f64().unwrap_or(0.0) as u64
-                    ))
-                    .to_string())
-                } else {
-                    Cell::new(value.as_str().unwrap_or("N/A"))
-                }
-            }),
+            Some(
+                |_output_type: OutputType, field: &str, value: &serde_json::Value| {
+                    if field.eq("duration") {
+                        cell!(indicatif::HumanDuration(Duration::from_secs(
+                            value.as_f64().unwrap_or(0.0) as u64
+                        ))
+                        .to_string())
+                    } else {
+                        Cell::new(value.as_str().unwrap_or("N/A"))
+                    }
+                },
+            ),
         )
     }
 }
@@ -602,7 +1202,7 @@ mod tests {
             app_id: "test_app".to_string(),
             settings: vec![Setting {
                 title: "username".to_string(),
-                type_: "string".to_string(),
+                type_: SettingType::String,
                 default_value: "stranger".to_string(),
                 optional: true,
                 help_text: "An example of a setting that is used in index.html".to_string(),
@@ -623,4 +1223,188 @@ mod tests {
 
         dir.close().unwrap();
     }
+
+    #[test]
+    fn test_base64_data_should_round_trip_as_url_safe_no_pad() {
+        let data = Base64Data(b"hello world".to_vec());
+        let encoded = data.to_string();
+
+        assert_eq!(encoded, "aGVsbG8gd29ybGQ");
+        assert_eq!(Base64Data::try_from(encoded.as_str()).unwrap(), data);
+    }
+
+    #[test]
+    fn test_base64_data_should_decode_any_known_flavor() {
+        let data = Base64Data(b"hello world".to_vec());
+
+        assert_eq!(Base64Data::try_from("aGVsbG8gd29ybGQ=").unwrap(), data); // standard
+        assert_eq!(Base64Data::try_from("aGVsbG8gd29ybGQ").unwrap(), data); // url-safe no-pad
+        assert_eq!(Base64Data::try_from("aGVsbG8g\r\nd29ybGQ=").unwrap(), data);
+        // MIME
+    }
+
+    #[test]
+    fn test_base64_data_should_return_parse_error_on_garbage_input() {
+        assert!(matches!(
+            Base64Data::try_from("not valid base64!!!"),
+            Err(CommandError::Base64Decode)
+        ));
+    }
+
+    #[test]
+    fn test_screens_format_should_render_csv_with_plain_booleans() {
+        let screens = Screens::new(serde_json::json!([
+            {
+                "id": "1",
+                "name": "Lobby",
+                "hardware_version": "1",
+                "in_sync": true,
+                "last_ping": "2023-01-01",
+                "uptime": 3600
+            }
+        ]));
+
+        let csv = screens.format(OutputType::Csv);
+        let header = csv.lines().next().unwrap();
+        let row = csv.lines().nth(1).unwrap();
+
+        assert_eq!(header, "Id,Name,Hardware Version,In Sync,Last Ping,Uptime");
+        assert!(row.starts_with("1,Lobby,1,true,2023-01-01,"));
+    }
+
+    #[test]
+    fn test_screens_format_should_render_human_readable_from_typed_rows() {
+        let screens = Screens::new(serde_json::json!([
+            {
+                "id": "1",
+                "name": "Lobby",
+                "hardware_version": "1",
+                "in_sync": true,
+                "last_ping": "2023-01-01",
+                "uptime": 3600
+            }
+        ]));
+
+        let table = screens.format(OutputType::HumanReadable);
+
+        assert!(table.contains("Lobby"));
+        assert!(table.contains('✅'));
+    }
+
+    #[test]
+    fn test_edge_apps_format_should_render_human_readable_from_typed_rows() {
+        let apps = EdgeApps::new(serde_json::json!([
+            { "id": "1", "name": "Weather" }
+        ]));
+
+        let table = apps.format(OutputType::HumanReadable);
+
+        assert!(table.contains("Weather"));
+    }
+
+    #[test]
+    fn test_assets_format_should_render_human_readable_from_typed_rows() {
+        let assets = Assets::new(serde_json::json!([
+            { "id": "1", "title": "intro.mp4", "type": "video", "status": "processed" }
+        ]));
+
+        let table = assets.format(OutputType::HumanReadable);
+
+        assert!(table.contains("intro.mp4"));
+        assert!(table.contains("processed"));
+    }
+
+    #[test]
+    fn test_setting_type_should_round_trip_known_and_unknown_discriminants() {
+        assert_eq!(SettingType::from("number"), SettingType::Number);
+        assert_eq!(SettingType::from("strnig").to_string(), "strnig");
+        assert_eq!(SettingType::Secret.to_string(), "secret");
+    }
+
+    #[test]
+    fn test_setting_type_should_reject_non_numeric_default_for_number_setting() {
+        assert!(matches!(
+            SettingType::Number.validate_default_value("not a number"),
+            Err(CommandError::InvalidSettingDefaultValue { .. })
+        ));
+        assert!(SettingType::Number.validate_default_value("42").is_ok());
+    }
+
+    #[test]
+    fn test_setting_type_should_accept_any_default_for_unknown_type() {
+        assert!(SettingType::Unknown("strnig".to_string())
+            .validate_default_value("anything")
+            .is_ok());
+    }
+
+    #[test]
+    fn test_is_retryable_status_should_differ_between_policies() {
+        assert!(is_retryable_status(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            RetryPolicy::Idempotent
+        ));
+        assert!(!is_retryable_status(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            RetryPolicy::NonIdempotent
+        ));
+        assert!(is_retryable_status(
+            StatusCode::TOO_MANY_REQUESTS,
+            RetryPolicy::NonIdempotent
+        ));
+        assert!(!is_retryable_status(
+            StatusCode::OK,
+            RetryPolicy::Idempotent
+        ));
+    }
+
+    #[test]
+    fn test_retry_after_delay_should_parse_both_delta_seconds_and_http_date() {
+        let mut headers = HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, "2".parse().unwrap());
+        assert_eq!(retry_after_delay(&headers), Some(Duration::from_secs(2)));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            "Sun, 06 Nov 1994 08:49:37 GMT".parse().unwrap(),
+        );
+        assert!(retry_after_delay(&headers).is_some());
+    }
+
+    #[test]
+    fn test_backoff_delay_should_never_exceed_the_configured_cap() {
+        let retry = RetryConfig {
+            max_attempts: DEFAULT_RETRY_MAX_ATTEMPTS,
+            base_delay: DEFAULT_RETRY_BASE_DELAY,
+            max_delay: DEFAULT_RETRY_MAX_DELAY,
+        };
+        for attempt in 0..10 {
+            assert!(backoff_delay(attempt, retry) <= retry.max_delay);
+        }
+    }
+
+    #[test]
+    fn test_is_retryable_transport_error_should_differ_between_policies() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            // Accept and hold the connection open without responding, so the
+            // client's request times out instead of being refused.
+            let _conn = listener.accept();
+            std::thread::sleep(Duration::from_secs(5));
+        });
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_millis(200))
+            .build()
+            .unwrap();
+        let err = client.get(format!("http://{addr}")).send().unwrap_err();
+
+        assert!(err.is_timeout());
+        assert!(is_retryable_transport_error(&err, RetryPolicy::Idempotent));
+        assert!(!is_retryable_transport_error(
+            &err,
+            RetryPolicy::NonIdempotent
+        ));
+    }
 }
\ No newline at end of file